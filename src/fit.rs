@@ -0,0 +1,80 @@
+use crate::{Orientation, Size};
+
+/// Strategy for fitting a [`Size`] within a bounding box.
+///
+/// Can be one of:
+/// - `Contain`: scale down uniformly so both dimensions fit inside the box, never upscaling
+/// - `Cover`: scale uniformly so the box is fully covered, dimensions may exceed one bound
+/// - `Fill`: force both dimensions to the box, ignoring aspect ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    Contain,
+    Cover,
+    Fill
+}
+
+impl Size {
+    /// Fits this `Size` within a `max_width` x `max_height` box according to `mode`,
+    /// recomputing `orientation` from the resulting dimensions and rounding half-up.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Fit, Size, Scale};
+    ///
+    /// let size = Size::new(1920, 1080, Scale::LG);
+    /// let fitted = size.fit_within(800, 800, Fit::Contain);
+    /// assert_eq!((fitted.width, fitted.height), (800, 450));
+    /// ```
+    pub fn fit_within(&self, max_width: i32, max_height: i32, mode: Fit) -> Self {
+        let (width, height) = match mode {
+            Fit::Contain if self.width == 0 || self.height == 0 => (0, 0),
+            Fit::Contain => {
+                let factor = (max_width as f64 / self.width as f64)
+                    .min(max_height as f64 / self.height as f64)
+                    .min(1.0);
+
+                (round_half_up(self.width as f64 * factor), round_half_up(self.height as f64 * factor))
+            }
+            Fit::Cover if self.width == 0 || self.height == 0 => (0, 0),
+            Fit::Cover => {
+                let factor = (max_width as f64 / self.width as f64)
+                    .max(max_height as f64 / self.height as f64);
+
+                (round_half_up(self.width as f64 * factor), round_half_up(self.height as f64 * factor))
+            }
+            Fit::Fill => (max_width, max_height)
+        };
+
+        let orientation = match width.cmp(&height) {
+            std::cmp::Ordering::Equal => Orientation::Thumbnail,
+            std::cmp::Ordering::Greater => Orientation::Landscape,
+            std::cmp::Ordering::Less => Orientation::Portrait
+        };
+
+        Self {
+            scale: self.scale,
+            orientation,
+            width,
+            height,
+            format: self.format
+        }
+    }
+
+    /// Convenience wrapper equal to `fit_within(max_width, max_height, Fit::Contain)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let size = Size::new(1920, 1080, Scale::LG);
+    /// let clamped = size.clamp_dimensions(800, 800);
+    /// assert_eq!((clamped.width, clamped.height), (800, 450));
+    /// ```
+    pub fn clamp_dimensions(&self, max_width: i32, max_height: i32) -> Self {
+        self.fit_within(max_width, max_height, Fit::Contain)
+    }
+}
+
+fn round_half_up(value: f64) -> i32 {
+    (value + 0.5).floor() as i32
+}
@@ -0,0 +1,167 @@
+use serde::de::Error;
+use std::fmt::{Display, Formatter, Result as StdResult};
+
+/// Represents the encoded image format attached to a `Size`.
+///
+/// Can be one of:
+/// - `Jpeg`
+/// - `Png`
+/// - `Webp`
+/// - `Avif`
+///
+/// # Example
+/// ```
+/// use sizes::ImageFormat;
+///
+/// let format = ImageFormat::from("webp");
+/// assert_eq!(format, ImageFormat::Webp);
+///
+/// let format_str = format.to_string();
+/// assert_eq!(format_str, "WEBP");
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "TEXT", rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum ImageFormat {
+    #[default]
+    Jpeg,
+    Png,
+    Webp,
+    Avif
+}
+
+impl ImageFormat {
+    /// Picks the best supported format for an HTTP `Accept` header value, preferring
+    /// `AVIF` over `WebP` over `JPEG`. Falls back to `Jpeg` when nothing matches.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::ImageFormat;
+    ///
+    /// let format = ImageFormat::from_accept_header("image/webp,image/avif,*/*");
+    /// assert_eq!(format, ImageFormat::Avif);
+    /// ```
+    pub fn from_accept_header(accept: &str) -> Self {
+        let accept = accept.to_lowercase();
+
+        if accept.contains("image/avif") {
+            ImageFormat::Avif
+        } else if accept.contains("image/webp") {
+            ImageFormat::Webp
+        } else {
+            ImageFormat::Jpeg
+        }
+    }
+
+    /// Returns the file extension conventionally used for this format.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::ImageFormat;
+    ///
+    /// assert_eq!(ImageFormat::Png.extension(), "png");
+    /// ```
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Avif => "avif"
+        }
+    }
+
+    /// Returns the IANA MIME type for this format.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::ImageFormat;
+    ///
+    /// assert_eq!(ImageFormat::Png.mime(), "image/png");
+    /// ```
+    pub fn mime(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Webp => "image/webp",
+            ImageFormat::Avif => "image/avif"
+        }
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> StdResult {
+        let variant_str = match self {
+            ImageFormat::Jpeg => "JPEG",
+            ImageFormat::Png => "PNG",
+            ImageFormat::Webp => "WEBP",
+            ImageFormat::Avif => "AVIF"
+        };
+
+        write!(f, "{}", variant_str)
+    }
+}
+
+impl serde::Serialize for ImageFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let variant_str = match self {
+            ImageFormat::Jpeg => "JPEG",
+            ImageFormat::Png => "PNG",
+            ImageFormat::Webp => "WEBP",
+            ImageFormat::Avif => "AVIF"
+        };
+
+        serializer.serialize_str(variant_str)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for ImageFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let variant = String::deserialize(deserializer)?;
+
+        match variant.to_lowercase().as_str() {
+            "jpeg" => Ok(ImageFormat::Jpeg),
+            "png" => Ok(ImageFormat::Png),
+            "webp" => Ok(ImageFormat::Webp),
+            "avif" => Ok(ImageFormat::Avif),
+            _ => Err(Error::unknown_variant(
+                &variant,
+                &["JPEG", "PNG", "WEBP", "AVIF"]
+            )),
+        }
+    }
+}
+
+impl From<String> for ImageFormat {
+    fn from(s: String) -> Self {
+        match s.to_lowercase().as_str() {
+            "png" => ImageFormat::Png,
+            "webp" => ImageFormat::Webp,
+            "avif" => ImageFormat::Avif,
+            _ => ImageFormat::Jpeg,
+        }
+    }
+}
+
+impl From<&String> for ImageFormat {
+    fn from(s: &String) -> Self {
+        ImageFormat::from(s.to_string())
+    }
+}
+
+impl From<&str> for ImageFormat {
+    fn from(s: &str) -> Self {
+        ImageFormat::from(s.to_string())
+    }
+}
+
+impl From<Option<String>> for ImageFormat {
+    fn from(s: Option<String>) -> Self {
+        match s {
+            Some(s) => ImageFormat::from(s),
+            None => ImageFormat::Jpeg,
+        }
+    }
+}
@@ -1,10 +1,17 @@
+mod fit;
+mod formats;
 mod orientations;
 mod scales;
 mod sizes;
+mod srcset;
+mod wire;
 
+pub use fit::Fit;
+pub use formats::ImageFormat;
 pub use orientations::Orientation;
 pub use scales::Scale;
 pub use sizes::Size;
+pub use wire::DecodeError;
 
 pub fn new_thumbnail(sz: i32, scale: Scale) -> Size {
     Size::new_thumbnail(sz, scale)
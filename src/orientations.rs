@@ -1,5 +1,4 @@
 use serde::de::Error;
-use sqlx::Type;
 use std::fmt::{Display, Formatter, Result as StdResult};
 
 /// Represents the orientation of an image.
@@ -19,8 +18,9 @@ use std::fmt::{Display, Formatter, Result as StdResult};
 /// let orientation_str = orientation.to_string();
 /// assert_eq!(orientation_str, "LANDSCAPE");
 /// ```
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Type)]
-#[sqlx(type_name = "TEXT", rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "TEXT", rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Orientation {
     #[default]
     Thumbnail,
@@ -1,7 +1,6 @@
 use std::fmt::{Display, Formatter, Result as StdResult};
 use serde::de::Error;
 use serde::Serialize;
-use sqlx::Type;
 
 /// Represents the size scale of an image.
 ///
@@ -24,8 +23,9 @@ use sqlx::Type;
 /// let scale_str = scale.to_string();
 /// assert_eq!(scale_str, "LG");
 /// ```
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Type)]
-#[sqlx(type_name = "TEXT")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "TEXT"))]
 pub enum Scale {
     #[default]
     XXSM,
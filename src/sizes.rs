@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Orientation, Scale};
+use crate::{ImageFormat, Orientation, Scale};
 
-/// Represents an image size with scale, orientation, and dimensions.
+/// Represents an image size with scale, orientation, dimensions, and encoded format.
 ///
 /// # Example
 /// ```
@@ -18,10 +18,42 @@ pub struct Size {
     pub scale: Scale,
     pub orientation: Orientation,
     pub width: i32,
-    pub height: i32
+    pub height: i32,
+    #[serde(default)]
+    pub format: ImageFormat
 }
 
 impl Size {
+    /// Creates a new `Size`, automatically deriving the orientation from the given
+    /// dimensions: `Thumbnail` when `width == height`, `Landscape` when `width > height`,
+    /// and `Portrait` when `height > width`.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Orientation, Size, Scale};
+    ///
+    /// let size = Size::new(1920, 1080, Scale::LG);
+    /// assert_eq!(size.orientation, Orientation::Landscape);
+    ///
+    /// let square = Size::new(100, 100, Scale::MD);
+    /// assert_eq!(square.orientation, Orientation::Thumbnail);
+    /// ```
+    pub fn new(width: i32, height: i32, scale: Scale) -> Self {
+        let orientation = match width.cmp(&height) {
+            std::cmp::Ordering::Equal => Orientation::Thumbnail,
+            std::cmp::Ordering::Greater => Orientation::Landscape,
+            std::cmp::Ordering::Less => Orientation::Portrait
+        };
+
+        Self {
+            scale,
+            orientation,
+            width,
+            height,
+            format: ImageFormat::default()
+        }
+    }
+
     /// Creates a new square thumbnail with the specified size and scale.
     ///
     /// # Example
@@ -38,7 +70,8 @@ impl Size {
             scale,
             orientation: Orientation::Thumbnail,
             width: sz,
-            height: sz
+            height: sz,
+            format: ImageFormat::default()
         }
     }
 
@@ -56,7 +89,8 @@ impl Size {
             scale,
             orientation: Orientation::Landscape,
             width: w,
-            height: h
+            height: h,
+            format: ImageFormat::default()
         }
     }
 
@@ -74,7 +108,8 @@ impl Size {
             scale,
             orientation: Orientation::Portrait,
             width: w,
-            height: h
+            height: h,
+            format: ImageFormat::default()
         }
     }
 
@@ -94,20 +129,68 @@ impl Size {
         *self == Self::default()
     }
 
+    /// Returns the width-to-height ratio, or `0.0` if the height is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let size = Size::new(1920, 1080, Scale::LG);
+    /// assert_eq!(size.aspect_ratio(), 1920.0 / 1080.0);
+    /// ```
+    pub fn aspect_ratio(&self) -> f64 {
+        if self.height == 0 {
+            return 0.0;
+        }
+
+        self.width as f64 / self.height as f64
+    }
+
+    /// Swaps width and height, flipping `Landscape` to `Portrait` and vice versa.
+    /// `Thumbnail` is left unchanged since a square has no orientation to flip.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Orientation, Size, Scale};
+    ///
+    /// let portrait = Size::new_portrait(800, 1200, Scale::MD).transpose();
+    /// assert_eq!(portrait.width, 1200);
+    /// assert_eq!(portrait.height, 800);
+    /// assert_eq!(portrait.orientation, Orientation::Landscape);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let orientation = match self.orientation {
+            Orientation::Landscape => Orientation::Portrait,
+            Orientation::Portrait => Orientation::Landscape,
+            Orientation::Thumbnail => Orientation::Thumbnail
+        };
+
+        Self {
+            scale: self.scale,
+            orientation,
+            width: self.height,
+            height: self.width,
+            format: self.format
+        }
+    }
+
 }
 
+#[cfg(feature = "sqlx")]
 impl sqlx::Type<sqlx::Postgres> for Size {
     fn type_info() -> sqlx::postgres::PgTypeInfo {
         <sqlx::types::Json<Self> as sqlx::Type<sqlx::Postgres>>::type_info()
     }
 }
 
+#[cfg(feature = "sqlx")]
 impl<'q> sqlx::Encode<'q, sqlx::Postgres> for Size {
     fn encode_by_ref(&self, buf: &mut sqlx::postgres::PgArgumentBuffer) -> Result<sqlx::encode::IsNull, Box<dyn serde::ser::StdError + Send + Sync + 'static>> {
         <sqlx::types::Json<&Self> as sqlx::Encode<'q, sqlx::Postgres>>::encode(sqlx::types::Json(self), buf)
     }
 }
 
+#[cfg(feature = "sqlx")]
 impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Size {
     fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         let bytes = value.as_str()?
@@ -0,0 +1,94 @@
+use crate::{Scale, Size};
+
+/// Single source-of-truth table mapping each [`Scale`] to the scaling factor it
+/// represents relative to `Scale::MD` (`1.0`). Tune these values here; every
+/// responsive-image helper on [`Size`] derives from this table.
+fn scale_factor(scale: Scale) -> f64 {
+    match scale {
+        Scale::XXSM => 0.125,
+        Scale::XSM => 0.25,
+        Scale::SM => 0.5,
+        Scale::MD => 1.0,
+        Scale::LG => 1.5,
+        Scale::XLG => 2.0,
+        Scale::XXLG => 3.0
+    }
+}
+
+impl Size {
+    /// Rescales this `Size` to the given target [`Scale`], multiplying width and
+    /// height by `target_factor / self.scale_factor`, rounding to the nearest
+    /// integer, and clamping both dimensions to a minimum of `1px`. Orientation is
+    /// preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let md = Size::new(1280, 720, Scale::MD);
+    /// let lg = md.rescale(Scale::LG);
+    /// assert_eq!((lg.width, lg.height), (1920, 1080));
+    /// ```
+    pub fn rescale(&self, target: Scale) -> Self {
+        let factor = scale_factor(target) / scale_factor(self.scale);
+
+        Self {
+            scale: target,
+            orientation: self.orientation,
+            width: ((self.width as f64 * factor).round() as i32).max(1),
+            height: ((self.height as f64 * factor).round() as i32).max(1),
+            format: self.format
+        }
+    }
+
+    /// Expands this `Size` across every [`Scale`] step, returning one `Size` per
+    /// variant in scale order (`XXSM` to `XXLG`).
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let set = Size::new(1280, 720, Scale::MD).responsive_set();
+    /// assert_eq!(set.len(), 7);
+    /// ```
+    pub fn responsive_set(&self) -> Vec<Self> {
+        [
+            Scale::XXSM,
+            Scale::XSM,
+            Scale::SM,
+            Scale::MD,
+            Scale::LG,
+            Scale::XLG,
+            Scale::XXLG
+        ]
+        .into_iter()
+        .map(|scale| self.rescale(scale))
+        .collect()
+    }
+
+    /// Builds an HTML `srcset` attribute value from this `Size`'s [`responsive_set`](Self::responsive_set),
+    /// substituting `{scale}`, `{w}`, and `{h}` into `url_template` for each variant,
+    /// in scale order (`XXSM` to `XXLG`), e.g. `"..., img-MD.jpg 1280w, img-LG.jpg 1920w, ..."`.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let srcset = Size::new(1280, 720, Scale::MD).srcset_string("img-{scale}.jpg");
+    /// assert!(srcset.contains("img-MD.jpg 1280w"));
+    /// ```
+    pub fn srcset_string(&self, url_template: &str) -> String {
+        self.responsive_set()
+            .iter()
+            .map(|size| {
+                let url = url_template
+                    .replace("{scale}", &size.scale.to_string())
+                    .replace("{w}", &size.width.to_string())
+                    .replace("{h}", &size.height.to_string());
+
+                format!("{} {}w", url, size.width)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
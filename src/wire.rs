@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter, Result as StdResult};
+
+use crate::{ImageFormat, Orientation, Scale, Size};
+
+const WIRE_VERSION: u8 = 2;
+const WIRE_LEN: usize = 12;
+
+/// Error returned when decoding a `Size` from its compact binary wire format fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte slice was not exactly 12 bytes long.
+    UnexpectedLength(usize),
+    /// The version/magic byte did not match a version this crate understands.
+    UnsupportedVersion(u8),
+    /// The `Scale` discriminant byte was out of range.
+    InvalidScale(u8),
+    /// The `Orientation` discriminant byte was out of range.
+    InvalidOrientation(u8),
+    /// The `ImageFormat` discriminant byte was out of range.
+    InvalidFormat(u8)
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> StdResult {
+        match self {
+            DecodeError::UnexpectedLength(len) => write!(f, "expected 12 bytes, got {}", len),
+            DecodeError::UnsupportedVersion(v) => write!(f, "unsupported wire version {}", v),
+            DecodeError::InvalidScale(v) => write!(f, "invalid scale discriminant {}", v),
+            DecodeError::InvalidOrientation(v) => write!(f, "invalid orientation discriminant {}", v),
+            DecodeError::InvalidFormat(v) => write!(f, "invalid format discriminant {}", v)
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn scale_discriminant(scale: Scale) -> u8 {
+    match scale {
+        Scale::XXSM => 0,
+        Scale::XSM => 1,
+        Scale::SM => 2,
+        Scale::MD => 3,
+        Scale::LG => 4,
+        Scale::XLG => 5,
+        Scale::XXLG => 6
+    }
+}
+
+fn scale_from_discriminant(value: u8) -> Result<Scale, DecodeError> {
+    match value {
+        0 => Ok(Scale::XXSM),
+        1 => Ok(Scale::XSM),
+        2 => Ok(Scale::SM),
+        3 => Ok(Scale::MD),
+        4 => Ok(Scale::LG),
+        5 => Ok(Scale::XLG),
+        6 => Ok(Scale::XXLG),
+        _ => Err(DecodeError::InvalidScale(value))
+    }
+}
+
+fn orientation_discriminant(orientation: Orientation) -> u8 {
+    match orientation {
+        Orientation::Thumbnail => 0,
+        Orientation::Landscape => 1,
+        Orientation::Portrait => 2
+    }
+}
+
+fn orientation_from_discriminant(value: u8) -> Result<Orientation, DecodeError> {
+    match value {
+        0 => Ok(Orientation::Thumbnail),
+        1 => Ok(Orientation::Landscape),
+        2 => Ok(Orientation::Portrait),
+        _ => Err(DecodeError::InvalidOrientation(value))
+    }
+}
+
+fn format_discriminant(format: ImageFormat) -> u8 {
+    match format {
+        ImageFormat::Jpeg => 0,
+        ImageFormat::Png => 1,
+        ImageFormat::Webp => 2,
+        ImageFormat::Avif => 3
+    }
+}
+
+fn format_from_discriminant(value: u8) -> Result<ImageFormat, DecodeError> {
+    match value {
+        0 => Ok(ImageFormat::Jpeg),
+        1 => Ok(ImageFormat::Png),
+        2 => Ok(ImageFormat::Webp),
+        3 => Ok(ImageFormat::Avif),
+        _ => Err(DecodeError::InvalidFormat(value))
+    }
+}
+
+impl Size {
+    /// Encodes this `Size` into a stable, version-tagged 12-byte wire format:
+    /// 1 byte version, 1 byte `Scale` discriminant, 1 byte `Orientation`
+    /// discriminant, 1 byte `ImageFormat` discriminant, then little-endian
+    /// `width` and `height` as `i32`s.
+    ///
+    /// This is independent of the JSON/Postgres text encodings and is suitable
+    /// as a compact, cache-key-friendly binary representation.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::{Size, Scale};
+    ///
+    /// let size = Size::new(1920, 1080, Scale::LG);
+    /// let bytes = size.to_bytes();
+    /// assert_eq!(bytes.len(), 12);
+    /// assert_eq!(Size::from_bytes(&bytes).unwrap(), size);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(WIRE_LEN);
+
+        bytes.push(WIRE_VERSION);
+        bytes.push(scale_discriminant(self.scale));
+        bytes.push(orientation_discriminant(self.orientation));
+        bytes.push(format_discriminant(self.format));
+        bytes.extend_from_slice(&self.width.to_le_bytes());
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+
+        bytes
+    }
+
+    /// Decodes a `Size` from the wire format produced by [`to_bytes`](Self::to_bytes),
+    /// rejecting unknown version bytes and out-of-range discriminants instead of panicking.
+    ///
+    /// # Example
+    /// ```
+    /// use sizes::Size;
+    ///
+    /// assert!(Size::from_bytes(&[]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() != WIRE_LEN {
+            return Err(DecodeError::UnexpectedLength(bytes.len()));
+        }
+
+        if bytes[0] != WIRE_VERSION {
+            return Err(DecodeError::UnsupportedVersion(bytes[0]));
+        }
+
+        let scale = scale_from_discriminant(bytes[1])?;
+        let orientation = orientation_from_discriminant(bytes[2])?;
+        let format = format_from_discriminant(bytes[3])?;
+        let width = i32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let height = i32::from_le_bytes(bytes[8..12].try_into().unwrap());
+
+        Ok(Self {
+            scale,
+            orientation,
+            width,
+            height,
+            format
+        })
+    }
+}